@@ -1,5 +1,17 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+use core::str::FromStr;
+
+/// The base58 alphabet used by Bitcoin, and by this crate's string encoding.
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Separates the ASCII prefix from the base58-encoded body in the string form.
+const SEPARATOR: char = '_';
+
 /// An ID construct based on base58 encoding.
 ///
 /// - `P`: the number of bytes of the id prefix, e.g. `usr = 3` for an id prefixed with `usr`
@@ -10,9 +22,16 @@
 pub struct ID<const P: usize, const E: usize, const N: usize>([u8; N]);
 
 impl<const P: usize, const E: usize, const N: usize> ID<P, E, N> {
+    /// Compile-time guarantee of the layout invariant `P + E <= N`, which
+    /// `prefix()`, `content()` and `suffix()` rely on. Referencing it from every
+    /// public constructor turns an impossible layout (e.g. `ID<5, 10, 8>`) into a
+    /// build error instead of a runtime slice-range panic.
+    const VALID: () = assert!(P + E <= N);
+
     /// Create a new ID from a byte array.
     #[inline]
     pub const fn new(bytes: [u8; N]) -> Self {
+        let () = Self::VALID;
         Self(bytes)
     }
 
@@ -39,6 +58,155 @@ impl<const P: usize, const E: usize, const N: usize> ID<P, E, N> {
     pub fn as_bytes(&self) -> &[u8] {
         &self.0
     }
+
+    /// Create an ID whose trailing `E`-byte extension stores a big-endian
+    /// timestamp, so that the raw bytes sort chronologically within a shared
+    /// prefix and content. The base58 string is not fixed-width, so its
+    /// lexicographic order does not always follow the byte order (a body of
+    /// `[0, 57]` renders as `1z`, `[0, 58]` as `121`).
+    ///
+    /// `millis` is serialized big-endian into the extension; when `E < 8` only
+    /// its least-significant `E` bytes are kept, and when `E > 8` the high bytes
+    /// are zero-padded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `content` is not exactly `N - P - E` bytes long.
+    pub fn with_timestamp(prefix: [u8; P], content: &[u8], millis: u64) -> Self {
+        let () = Self::VALID;
+        let mut bytes = [0u8; N];
+        bytes[..P].copy_from_slice(&prefix);
+        bytes[P..N - E].copy_from_slice(content);
+        let be = millis.to_be_bytes();
+        let ext = &mut bytes[N - E..];
+        if E <= 8 {
+            ext.copy_from_slice(&be[8 - E..]);
+        } else {
+            let (pad, tail) = ext.split_at_mut(E - 8);
+            pad.fill(0);
+            tail.copy_from_slice(&be);
+        }
+        Self(bytes)
+    }
+
+    /// Read back the big-endian timestamp stored in the `E`-byte extension by
+    /// [`with_timestamp`](Self::with_timestamp).
+    pub fn timestamp(&self) -> u64 {
+        let ext = &self.0[N - E..];
+        let mut buf = [0u8; 8];
+        if E <= 8 {
+            buf[8 - E..].copy_from_slice(ext);
+        } else {
+            buf.copy_from_slice(&ext[E - 8..]);
+        }
+        u64::from_be_bytes(buf)
+    }
+
+    /// The raw bytes are the natural sort key: because the timestamp occupies
+    /// the trailing (least-significant) `E` bytes, the derived [`Ord`] already
+    /// orders IDs by prefix, then content, then time. Returns the slice that
+    /// ordering compares, for use as an explicit key in sorted stores.
+    #[inline]
+    pub fn sortable_by_time(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Read exactly `N` bytes from `r` via `read_exact` into a new ID, without
+    /// an intermediate buffer.
+    #[cfg(feature = "std")]
+    pub fn read_bytes<R: std::io::Read>(r: &mut R) -> std::io::Result<Self> {
+        let mut bytes = [0u8; N];
+        r.read_exact(&mut bytes)?;
+        Ok(Self::new(bytes))
+    }
+
+    /// Write the `N` backing bytes to `w`, returning the number of bytes written.
+    #[cfg(feature = "std")]
+    pub fn write_bytes<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<usize> {
+        w.write_all(&self.0)?;
+        Ok(N)
+    }
+
+    /// Build a [`Prefix`] from the first `len` bytes of this ID, for partial
+    /// matching and range scans.
+    pub fn prefix_of(&self, len: usize) -> Prefix<P, E, N> {
+        let mut bytes = [0u8; N];
+        bytes[..len].copy_from_slice(&self.0[..len]);
+        Prefix { bytes, len }
+    }
+}
+
+/// A partial [`ID`] — the first `len` bytes of some full ID — used to match and
+/// to bound key-range scans in sorted stores without materializing every ID.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct Prefix<const P: usize, const E: usize, const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const P: usize, const E: usize, const N: usize> Prefix<P, E, N> {
+    /// The meaningful byte span of this prefix (its first `len` bytes).
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Whether `id` shares this prefix, comparing only the first `len` bytes.
+    #[inline]
+    pub fn matches(&self, id: &ID<P, E, N>) -> bool {
+        self.bytes[..self.len] == id.0[..self.len]
+    }
+
+    /// The smallest full [`ID`] in this prefix's range, padding the remaining
+    /// bytes with `0x00`.
+    pub fn min_id(&self) -> ID<P, E, N> {
+        let mut bytes = [0x00u8; N];
+        bytes[..self.len].copy_from_slice(&self.bytes[..self.len]);
+        ID::new(bytes)
+    }
+
+    /// The largest full [`ID`] in this prefix's range, padding the remaining
+    /// bytes with `0xFF`.
+    pub fn max_id(&self) -> ID<P, E, N> {
+        let mut bytes = [0xFFu8; N];
+        bytes[..self.len].copy_from_slice(&self.bytes[..self.len]);
+        ID::new(bytes)
+    }
+}
+
+/// Parses a *full* ID string into a `Prefix` of length `N`.
+///
+/// The body must decode to exactly `N - P` bytes, just like [`FromStr for
+/// ID`](ID::from_str). Base58 is a base-58→base-256 conversion, not a
+/// byte-aligned encoding, so a truncated base58 string does **not** correspond
+/// to a byte-prefix of the original ID — parsing a shortened string could not
+/// produce a `Prefix` that `matches()` the ID it came from. To obtain a
+/// shorter prefix, parse (or construct) the full ID and call
+/// [`ID::prefix_of`], which truncates on byte boundaries.
+impl<const P: usize, const E: usize, const N: usize> FromStr for Prefix<P, E, N> {
+    type Err = ParseIDError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Split at the known `P`-byte prefix boundary, not the first `_`, since a
+        // separator byte may legally occur inside the prefix.
+        if s.as_bytes().get(P) != Some(&(SEPARATOR as u8)) {
+            return Err(ParseIDError::prefix_mismatch(
+                P,
+                s.find(SEPARATOR).unwrap_or(s.len()),
+            ));
+        }
+        let (prefix, rest) = s.split_at(P);
+        let body = &rest[1..];
+        let decoded = decode(body)
+            .map_err(|(character, index)| ParseIDError::invalid_char(character, index))?;
+        if decoded.len() != N - P {
+            return Err(ParseIDError::decoded_length(N - P, decoded.len()));
+        }
+        let mut bytes = [0u8; N];
+        bytes[..P].copy_from_slice(prefix.as_bytes());
+        bytes[P..].copy_from_slice(&decoded);
+        Ok(Self { bytes, len: N })
+    }
 }
 
 impl<const P: usize, const E: usize, const N: usize> From<[u8; N]> for ID<P, E, N> {
@@ -53,26 +221,264 @@ impl<const P: usize, const E: usize, const N: usize> TryFrom<&[u8]> for ID<P, E,
 
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
         if value.len() != N {
-            return Err(ParseIDError {
-                n: N,
-                src: value.len(),
-            });
+            return Err(ParseIDError::byte_length(N, value.len()));
         }
         Ok(value.try_into().map(Self::new).unwrap())
     }
 }
 
+/// Renders the `P`-byte prefix verbatim as ASCII, the separator `_`, then the
+/// base58 encoding of the content and extension (`content()` + `suffix()`).
+///
+/// The prefix bytes are assumed to be ASCII (e.g. `usr`). A byte in `0x80..=0xFF`
+/// renders as multi-byte UTF-8, which would no longer round-trip through
+/// [`FromStr`] (whose prefix check counts bytes); keep prefixes ASCII.
+impl<const P: usize, const E: usize, const N: usize> core::fmt::Display for ID<P, E, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for &b in &self.0[..P] {
+            f.write_char(b as char)?;
+        }
+        f.write_char(SEPARATOR)?;
+        f.write_str(&encode(&self.0[P..]))
+    }
+}
+
+/// Parses the string form produced by [`Display`](core::fmt::Display): the ASCII
+/// prefix, the separator `_`, then a base58 body decoding to exactly `N - P` bytes.
+///
+/// The prefix is taken to be ASCII; its length is checked in bytes, so a
+/// non-ASCII prefix will not round-trip (see [`Display`](core::fmt::Display)).
+impl<const P: usize, const E: usize, const N: usize> FromStr for ID<P, E, N> {
+    type Err = ParseIDError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The prefix is exactly `P` bytes, so split at that boundary rather than
+        // on the first `_`: a separator byte may legally occur inside the prefix.
+        if s.as_bytes().get(P) != Some(&(SEPARATOR as u8)) {
+            return Err(ParseIDError::prefix_mismatch(
+                P,
+                s.find(SEPARATOR).unwrap_or(s.len()),
+            ));
+        }
+        let (prefix, rest) = s.split_at(P);
+        let body = &rest[1..];
+        let decoded = decode(body)
+            .map_err(|(character, index)| ParseIDError::invalid_char(character, index))?;
+        if decoded.len() != N - P {
+            return Err(ParseIDError::decoded_length(N - P, decoded.len()));
+        }
+        let mut bytes = [0u8; N];
+        bytes[..P].copy_from_slice(prefix.as_bytes());
+        bytes[P..].copy_from_slice(&decoded);
+        Ok(Self::new(bytes))
+    }
+}
+
+/// Base58-encode a byte slice using the Bitcoin alphabet.
+fn encode(input: &[u8]) -> String {
+    let zeros = input.iter().take_while(|&&b| b == 0).count();
+    let mut buf = input.to_vec();
+    let mut out = Vec::new();
+    let mut start = zeros;
+    while start < buf.len() {
+        let mut remainder = 0u32;
+        for byte in &mut buf[start..] {
+            let acc = remainder * 256 + *byte as u32;
+            *byte = (acc / 58) as u8;
+            remainder = acc % 58;
+        }
+        out.push(ALPHABET[remainder as usize]);
+        if buf[start] == 0 {
+            start += 1;
+        }
+    }
+    out.resize(out.len() + zeros, b'1');
+    out.reverse();
+    // `out` only ever contains alphabet bytes, all of which are valid ASCII.
+    String::from_utf8(out).unwrap()
+}
+
+/// Base58-decode a string, returning the offending `(character, index)` on an
+/// unknown character.
+fn decode(input: &str) -> Result<Vec<u8>, (char, usize)> {
+    let zeros = input.bytes().take_while(|&b| b == b'1').count();
+    let mut bytes: Vec<u8> = Vec::new();
+    for (index, character) in input.char_indices() {
+        let value = ALPHABET
+            .iter()
+            .position(|&b| b as char == character)
+            .ok_or((character, index))?;
+        let mut carry = value as u32;
+        for byte in &mut bytes {
+            carry += *byte as u32 * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+    bytes.resize(bytes.len() + zeros, 0);
+    bytes.reverse();
+    Ok(bytes)
+}
+
+/// An error produced while parsing an [`ID`] from bytes or from its string form.
+///
+/// The underlying [`ParseIDErrorKind`] pinpoints the exact failure — including
+/// the offending character and its index for a malformed base58 body — so that
+/// callers parsing user-submitted IDs can surface actionable messages.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub struct ParseIDError {
-    n: usize,
-    src: usize,
+    kind: ParseIDErrorKind,
+}
+
+/// The specific reason a [`ParseIDError`] occurred.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum ParseIDErrorKind {
+    /// A byte slice did not have the expected length `N`.
+    ByteLength { expected: usize, got: usize },
+    /// A string had no separator `_` right after its first `P` bytes; `found`
+    /// is the byte offset of its first `_`, or its length if it has none.
+    PrefixMismatch { expected: usize, found: usize },
+    /// The base58 body contained a character outside the alphabet.
+    InvalidBase58Char { character: char, index: usize },
+    /// The base58 body decoded to a length other than `N - P`.
+    DecodedLength { expected: usize, got: usize },
+}
+
+impl ParseIDError {
+    /// Returns the structured reason this error occurred.
+    #[inline]
+    pub fn kind(&self) -> ParseIDErrorKind {
+        self.kind
+    }
+
+    #[inline]
+    fn byte_length(expected: usize, got: usize) -> Self {
+        Self {
+            kind: ParseIDErrorKind::ByteLength { expected, got },
+        }
+    }
+
+    #[inline]
+    fn prefix_mismatch(expected: usize, found: usize) -> Self {
+        Self {
+            kind: ParseIDErrorKind::PrefixMismatch { expected, found },
+        }
+    }
+
+    #[inline]
+    fn invalid_char(character: char, index: usize) -> Self {
+        Self {
+            kind: ParseIDErrorKind::InvalidBase58Char { character, index },
+        }
+    }
+
+    #[inline]
+    fn decoded_length(expected: usize, got: usize) -> Self {
+        Self {
+            kind: ParseIDErrorKind::DecodedLength { expected, got },
+        }
+    }
 }
 
 impl core::fmt::Display for ParseIDError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "buid: expected {} bytes, got {}", self.n, self.src)
+        match self.kind {
+            ParseIDErrorKind::ByteLength { expected, got } => {
+                write!(f, "buid: expected {expected} bytes, got {got}")
+            }
+            ParseIDErrorKind::PrefixMismatch { expected, found } => {
+                write!(f, "buid: expected a {expected}-byte prefix, got {found}")
+            }
+            ParseIDErrorKind::InvalidBase58Char { character, index } => {
+                write!(
+                    f,
+                    "buid: invalid base58 character {character:?} at index {index}"
+                )
+            }
+            ParseIDErrorKind::DecodedLength { expected, got } => {
+                write!(f, "buid: expected a {expected}-byte base58 body, got {got}")
+            }
+        }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for ParseIDError {}
+
+/// Serializes as the base58 prefixed string for human-readable formats (JSON,
+/// YAML) and as the raw `[u8; N]` byte array otherwise (bincode, CBOR).
+#[cfg(feature = "serde")]
+impl<const P: usize, const E: usize, const N: usize> serde::Serialize for ID<P, E, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&alloc::string::ToString::to_string(self))
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+
+/// Accepts either the base58 prefixed string or the raw `[u8; N]` byte array,
+/// round-tripping through [`FromStr`] / [`TryFrom<&[u8]>`] and surfacing the
+/// structured [`ParseIDError`] diagnostics as a serde error.
+#[cfg(feature = "serde")]
+impl<'de, const P: usize, const E: usize, const N: usize> serde::Deserialize<'de>
+    for ID<P, E, N>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct IDVisitor<const P: usize, const E: usize, const N: usize>;
+
+        impl<'de, const P: usize, const E: usize, const N: usize> serde::de::Visitor<'de>
+            for IDVisitor<P, E, N>
+        {
+            type Value = ID<P, E, N>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "a base58 buid string or {N} raw bytes")
+            }
+
+            fn visit_str<Er>(self, value: &str) -> Result<Self::Value, Er>
+            where
+                Er: serde::de::Error,
+            {
+                value.parse().map_err(Er::custom)
+            }
+
+            fn visit_bytes<Er>(self, value: &[u8]) -> Result<Self::Value, Er>
+            where
+                Er: serde::de::Error,
+            {
+                ID::try_from(value).map_err(Er::custom)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut bytes = [0u8; N];
+                for (i, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq
+                        .next_element()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(i, &self))?;
+                }
+                Ok(ID::new(bytes))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(IDVisitor)
+        } else {
+            deserializer.deserialize_bytes(IDVisitor)
+        }
+    }
+}